@@ -0,0 +1,51 @@
+// The binary is deliberately thin: all of the behavior lives in the
+// `restaurant` library crate, and this just drives a demo dinner service
+// through its public API. Pulling in the prelude flattens the deep paths.
+use restaurant::garden::herbs::Basil;
+use restaurant::garden::vegetables::{Asparagus, Carrot};
+use restaurant::prelude::*;
+
+fn main() -> Result<(), RestaurantError> {
+    demo_garden();
+
+    let mut restaurant = Restaurant::new(3);
+
+    // a plant from the garden module, to show it is still reachable
+    let plant = Asparagus {};
+    println!("today's special comes with {plant:?}");
+
+    add_to_waitlist(&mut restaurant, "Aziz, party of 2");
+    let table = seat_at_table(&mut restaurant)?;
+    println!("seated at table {table}");
+
+    take_order(
+        &mut restaurant,
+        table,
+        vec![
+            LineItem::new("soup", 2, 650),
+            LineItem::new("bread", 1, 300),
+        ],
+    )?;
+    serve_order(&mut restaurant, table)?;
+    let paid = take_payment(&mut restaurant, table)?;
+    println!("table {table} paid {paid} cents");
+
+    Ok(())
+}
+
+// Plant a mixed bed and report what is ready to pick. Carrot matures on day 70
+// exactly, so asking on day 70 exercises the boundary `day == days_to_maturity`
+// (`is_ready` is inclusive): basil (50) and carrot (70) are ready, asparagus
+// (730) is not.
+fn demo_garden() {
+    let mut garden = Garden::new();
+    garden.register(Box::new(Basil {}));
+    garden.register(Box::new(Carrot {}));
+    garden.register(Box::new(Asparagus {}));
+
+    let ready = garden.ready_to_harvest(70);
+    let names: Vec<&str> = ready.iter().map(|plant| plant.name()).collect();
+    assert_eq!(names, ["basil", "carrot"]);
+
+    println!("ready to harvest on day 70: {names:?}");
+}
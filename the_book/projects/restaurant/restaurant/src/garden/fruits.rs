@@ -0,0 +1,35 @@
+use super::{Plant, PlantKind};
+
+#[derive(Debug)]
+pub struct Strawberry {}
+
+impl Plant for Strawberry {
+    fn name(&self) -> &str {
+        "strawberry"
+    }
+
+    fn days_to_maturity(&self) -> u32 {
+        90
+    }
+
+    fn kind(&self) -> PlantKind {
+        PlantKind::Fruit
+    }
+}
+
+#[derive(Debug)]
+pub struct Tomato {}
+
+impl Plant for Tomato {
+    fn name(&self) -> &str {
+        "tomato"
+    }
+
+    fn days_to_maturity(&self) -> u32 {
+        60
+    }
+
+    fn kind(&self) -> PlantKind {
+        PlantKind::Fruit
+    }
+}
@@ -0,0 +1,35 @@
+use super::{Plant, PlantKind};
+
+#[derive(Debug)]
+pub struct Basil {}
+
+impl Plant for Basil {
+    fn name(&self) -> &str {
+        "basil"
+    }
+
+    fn days_to_maturity(&self) -> u32 {
+        50
+    }
+
+    fn kind(&self) -> PlantKind {
+        PlantKind::Herb
+    }
+}
+
+#[derive(Debug)]
+pub struct Thyme {}
+
+impl Plant for Thyme {
+    fn name(&self) -> &str {
+        "thyme"
+    }
+
+    fn days_to_maturity(&self) -> u32 {
+        85
+    }
+
+    fn kind(&self) -> PlantKind {
+        PlantKind::Herb
+    }
+}
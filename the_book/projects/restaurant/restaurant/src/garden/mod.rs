@@ -0,0 +1,58 @@
+// `pub mod garden;` in lib.rs points the compiler at this directory's mod.rs. From
+// here the catalog is split across one file per plant family, each pulled in
+// with `mod` — the compiler then looks in src/garden/{name}.rs for each.
+pub mod fruits;
+pub mod herbs;
+pub mod vegetables;
+
+// How a plant is classified in the catalog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlantKind {
+    Vegetable,
+    Fruit,
+    Herb,
+}
+
+// Everything growable in the garden implements `Plant`, so the `Garden`
+// collection can treat a mixed bed uniformly behind `dyn Plant`.
+pub trait Plant {
+    // the plant's common name
+    fn name(&self) -> &str;
+
+    // how many days from planting until it can be harvested
+    fn days_to_maturity(&self) -> u32;
+
+    // which family it belongs to
+    fn kind(&self) -> PlantKind;
+
+    // whether the plant is ready to pick `day` days after planting
+    fn is_ready(&self, day: u32) -> bool {
+        day >= self.days_to_maturity()
+    }
+}
+
+// A bed of registered plants, held as trait objects so families can mix.
+#[derive(Default)]
+pub struct Garden {
+    plants: Vec<Box<dyn Plant>>,
+}
+
+impl Garden {
+    pub fn new() -> Garden {
+        Garden::default()
+    }
+
+    // add a plant to the bed
+    pub fn register(&mut self, plant: Box<dyn Plant>) {
+        self.plants.push(plant);
+    }
+
+    // every plant that is ready to harvest `day` days after planting
+    pub fn ready_to_harvest(&self, day: u32) -> Vec<&dyn Plant> {
+        self.plants
+            .iter()
+            .map(Box::as_ref)
+            .filter(|plant| plant.is_ready(day))
+            .collect()
+    }
+}
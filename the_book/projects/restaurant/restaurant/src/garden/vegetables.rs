@@ -0,0 +1,36 @@
+use super::{Plant, PlantKind};
+
+// A vegetable you can grow in the garden.
+#[derive(Debug)]
+pub struct Asparagus {}
+
+impl Plant for Asparagus {
+    fn name(&self) -> &str {
+        "asparagus"
+    }
+
+    fn days_to_maturity(&self) -> u32 {
+        730
+    }
+
+    fn kind(&self) -> PlantKind {
+        PlantKind::Vegetable
+    }
+}
+
+#[derive(Debug)]
+pub struct Carrot {}
+
+impl Plant for Carrot {
+    fn name(&self) -> &str {
+        "carrot"
+    }
+
+    fn days_to_maturity(&self) -> u32 {
+        70
+    }
+
+    fn kind(&self) -> PlantKind {
+        PlantKind::Vegetable
+    }
+}
@@ -0,0 +1,326 @@
+/* this project has the following 'module tree'
+crate
+ ├── prelude               (pub)
+ ├── garden                (pub)
+ │   ├── fruits
+ │   ├── herbs
+ │   └── vegetables
+ └── front_of_house        (private wrapper)
+     ├── hosting           (pub)
+     │   ├── add_to_waitlist
+     │   └── seat_at_table
+     └── serving           (pub)
+         ├── take_order
+         ├── serve_order
+         └── take_payment
+the 'crate root' is the root module of this structure and made from lib.rs
+If module A is contained in B, then A is a 'child' and B is a 'parent'
+
+`front_of_house` itself stays *private*: code outside the crate cannot name
+`crate::front_of_house::...` at all. The order subsystem (`Order`,
+`Restaurant`, `OrderState`, ...) lives beside it at the crate root. The
+front-of-house functions used to be empty stubs; they now drive a small
+order-lifecycle state machine, and the prelude below re-exports them with
+`pub use` so consumers reach them by short name without the private path ever
+leaking — the public/private boundary the chunk is teaching is preserved.
+*/
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub mod garden;
+
+// A flattened facade over the crate. The module tree below stays private and
+// deeply nested so the internal organization can keep changing, but consumers
+// only have to reach for the short names re-exported here:
+//
+//     use restaurant::prelude::*;
+//     let mut r = Restaurant::new(4);
+//     add_to_waitlist(&mut r, "party");
+//
+// This is the "re-exporting names with `pub use`" technique: `pub use` brings a
+// name into scope *and* makes it part of this module's public interface.
+pub mod prelude {
+    pub use crate::eat_at_restaurant;
+    pub use crate::garden::vegetables::Asparagus;
+    pub use crate::garden::{Garden, Plant, PlantKind};
+    pub use crate::front_of_house::hosting::{add_to_waitlist, seat_at_table};
+    pub use crate::front_of_house::serving::{serve_order, take_order, take_payment};
+    pub use crate::{LineItem, Order, OrderState, Restaurant, RestaurantError};
+}
+
+// Lift the most-used entry point straight to the crate root as well, so callers
+// can write `restaurant::add_to_waitlist()` without the private path.
+pub use front_of_house::hosting::add_to_waitlist;
+
+// The lifecycle an order moves through. Each transition is only legal from the
+// immediately preceding state; anything else is rejected as an error rather
+// than silently ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderState {
+    Pending,
+    Placed,
+    Served,
+    Paid,
+}
+
+// Something a party asked for, with how many of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineItem {
+    pub name: String,
+    pub quantity: u32,
+    // price in whole cents, kept as an integer to avoid float rounding
+    pub price_cents: u32,
+}
+
+impl LineItem {
+    pub fn new(name: impl Into<String>, quantity: u32, price_cents: u32) -> LineItem {
+        LineItem {
+            name: name.into(),
+            quantity,
+            price_cents,
+        }
+    }
+
+    // what this line contributes to the bill
+    pub fn subtotal_cents(&self) -> u32 {
+        self.price_cents * self.quantity
+    }
+}
+
+// A single table's order as it moves through the kitchen.
+#[derive(Debug, Clone)]
+pub struct Order {
+    pub id: u32,
+    pub table: u32,
+    pub items: Vec<LineItem>,
+    state: OrderState,
+}
+
+impl Order {
+    // the running total of every line item, in cents
+    pub fn total_cents(&self) -> u32 {
+        self.items.iter().map(LineItem::subtotal_cents).sum()
+    }
+
+    pub fn state(&self) -> OrderState {
+        self.state
+    }
+}
+
+// The ways a request against the restaurant can fail. Illegal state-machine
+// transitions carry the state we were actually in so the caller can report it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RestaurantError {
+    WaitlistEmpty,
+    NoFreeTable,
+    NoOrderForTable(u32),
+    IllegalTransition {
+        table: u32,
+        from: OrderState,
+        expected: OrderState,
+    },
+}
+
+impl std::fmt::Display for RestaurantError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RestaurantError::WaitlistEmpty => write!(f, "no party is waiting to be seated"),
+            RestaurantError::NoFreeTable => write!(f, "every table is occupied"),
+            RestaurantError::NoOrderForTable(table) => {
+                write!(f, "no order is open for table {table}")
+            }
+            RestaurantError::IllegalTransition {
+                table,
+                from,
+                expected,
+            } => write!(
+                f,
+                "order at table {table} is {from:?}, expected {expected:?}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RestaurantError {}
+
+// The whole dining room: who is waiting, which tables are taken, and every
+// order currently in flight keyed by its table number.
+#[derive(Debug)]
+pub struct Restaurant {
+    tables: u32,
+    waitlist: VecDeque<String>,
+    occupied: HashSet<u32>,
+    orders: HashMap<u32, Order>,
+    completed: Vec<Order>,
+    next_order_id: u32,
+}
+
+impl Restaurant {
+    // A restaurant with `tables` tables numbered 1..=tables.
+    pub fn new(tables: u32) -> Restaurant {
+        Restaurant {
+            tables,
+            waitlist: VecDeque::new(),
+            occupied: HashSet::new(),
+            orders: HashMap::new(),
+            completed: Vec::new(),
+            next_order_id: 1,
+        }
+    }
+
+    // the order open at a table, if any
+    pub fn order_at(&self, table: u32) -> Option<&Order> {
+        self.orders.get(&table)
+    }
+
+    // every `Paid` order, oldest first, kept on the books after the table was
+    // freed
+    pub fn completed_orders(&self) -> &[Order] {
+        &self.completed
+    }
+
+    // smallest unoccupied table number, or None when we are full
+    fn first_free_table(&self) -> Option<u32> {
+        (1..=self.tables).find(|table| !self.occupied.contains(table))
+    }
+}
+
+// `front_of_house` is declared with a bare `mod`, so it is private to the crate
+// root: nothing outside this crate can name `crate::front_of_house::...`. The
+// public entry points escape only through the `pub use` re-exports above. Its
+// children are `pub mod`s so the *rest of the crate* (e.g. `eat_at_restaurant`
+// and the prelude) can still reach them through the private parent.
+mod front_of_house {
+    // making the hosting mod public lets the crate root refer to it through the
+    // private `front_of_house` parent
+    pub mod hosting {
+        use crate::{Order, OrderState, Restaurant, RestaurantError};
+
+        // if we also want the contents of the public mod to be available,
+        // we must explicitly make those public as well
+        pub fn add_to_waitlist(restaurant: &mut Restaurant, party: impl Into<String>) {
+            restaurant.waitlist.push_back(party.into());
+        }
+        // these privacy rules apply to structs, enums, functions
+        // and other modules defined within the module
+
+        // Seat the next waiting party at a free table, opening a fresh `Pending`
+        // order for it. Errors if nobody is waiting or the room is full.
+        pub fn seat_at_table(restaurant: &mut Restaurant) -> Result<u32, RestaurantError> {
+            if restaurant.waitlist.is_empty() {
+                return Err(RestaurantError::WaitlistEmpty);
+            }
+            let table = restaurant
+                .first_free_table()
+                .ok_or(RestaurantError::NoFreeTable)?;
+
+            restaurant.waitlist.pop_front();
+            restaurant.occupied.insert(table);
+
+            let id = restaurant.next_order_id;
+            restaurant.next_order_id += 1;
+            restaurant.orders.insert(
+                table,
+                Order {
+                    id,
+                    table,
+                    items: Vec::new(),
+                    state: OrderState::Pending,
+                },
+            );
+            Ok(table)
+        }
+    }
+
+    pub mod serving {
+        use crate::{LineItem, OrderState, Restaurant, RestaurantError};
+
+        // shared helper: fetch the open order for a table, checking it is in the
+        // state a transition expects before handing back a mutable borrow
+        fn expect_state(
+            restaurant: &mut Restaurant,
+            table: u32,
+            expected: OrderState,
+        ) -> Result<&mut crate::Order, RestaurantError> {
+            let order = restaurant
+                .orders
+                .get_mut(&table)
+                .ok_or(RestaurantError::NoOrderForTable(table))?;
+            if order.state != expected {
+                return Err(RestaurantError::IllegalTransition {
+                    table,
+                    from: order.state,
+                    expected,
+                });
+            }
+            Ok(order)
+        }
+
+        // Record the party's choices and move the order from `Pending` to `Placed`.
+        pub fn take_order(
+            restaurant: &mut Restaurant,
+            table: u32,
+            items: Vec<LineItem>,
+        ) -> Result<(), RestaurantError> {
+            let order = expect_state(restaurant, table, OrderState::Pending)?;
+            order.items = items;
+            order.state = OrderState::Placed;
+            Ok(())
+        }
+
+        // Bring the food out: `Placed` → `Served`.
+        pub fn serve_order(restaurant: &mut Restaurant, table: u32) -> Result<(), RestaurantError> {
+            let order = expect_state(restaurant, table, OrderState::Placed)?;
+            order.state = OrderState::Served;
+            Ok(())
+        }
+
+        // Settle the bill: `Served` → `Paid`, free the table for the next party,
+        // and move the now-`Paid` order onto the completed list so it stays on
+        // the books. Returns the amount paid, in cents.
+        pub fn take_payment(
+            restaurant: &mut Restaurant,
+            table: u32,
+        ) -> Result<u32, RestaurantError> {
+            let total = {
+                let order = expect_state(restaurant, table, OrderState::Served)?;
+                order.state = OrderState::Paid;
+                order.total_cents()
+            };
+
+            restaurant.occupied.remove(&table);
+            let paid = restaurant
+                .orders
+                .remove(&table)
+                .expect("order was just validated above");
+            restaurant.completed.push(paid);
+            Ok(total)
+        }
+    }
+}
+
+// `front_of_house` is a sibling module of this function, so even though it is
+// private we can still walk a whole dinner service through it: seat a party,
+// take and serve their order, then take payment.
+pub fn eat_at_restaurant() -> Result<(), RestaurantError> {
+    use front_of_house::{hosting, serving};
+
+    let mut restaurant = Restaurant::new(2);
+
+    hosting::add_to_waitlist(&mut restaurant, "Aziz, party of 2");
+    let table = hosting::seat_at_table(&mut restaurant)?;
+
+    serving::take_order(
+        &mut restaurant,
+        table,
+        vec![
+            LineItem::new("soup", 2, 650),
+            LineItem::new("bread", 1, 300),
+        ],
+    )?;
+    serving::serve_order(&mut restaurant, table)?;
+    let paid = serving::take_payment(&mut restaurant, table)?;
+    println!("table {table} paid {paid} cents");
+
+    Ok(())
+}